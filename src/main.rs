@@ -9,29 +9,40 @@ use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Sparkline, Table},
 };
 use std::{
     collections::{HashMap, VecDeque},
-    io,
-    net::UdpSocket,
-    path::Path,
+    ffi::CString,
+    fs,
+    io::{self, Read, Write},
+    net::{TcpListener, TcpStream, UdpSocket},
+    os::unix::ffi::OsStrExt,
+    path::{Path, PathBuf},
     process::Command,
+    sync::{Arc, Mutex},
+    thread,
     time::{Duration, Instant},
 };
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use sysinfo::{
-    CpuRefreshKind, Disks, LoadAvg, Networks, ProcessRefreshKind, ProcessesToUpdate, System,
-    UpdateKind,
+    Components, CpuRefreshKind, Disks, LoadAvg, Networks, ProcessRefreshKind, ProcessesToUpdate,
+    System, UpdateKind,
 };
 
 const MAX_CPU_HISTORY: usize = 240;
 const MAX_NET_HISTORY: usize = 180;
+const MAX_SENSOR_HISTORY: usize = 60;
+const MAX_DISK_HISTORY: usize = 60;
 const MIN_TICK_MS: u64 = 200;
 const MAX_TICK_MS: u64 = 5000;
 const TICK_STEP_MS: u64 = 100;
 const TARGET_FPS: u64 = 60;
 const FRAME_BUDGET_NS: u64 = 1_000_000_000 / TARGET_FPS;
 const MAX_INPUT_EVENTS_PER_CYCLE: usize = 64;
+const HELP_TEXT_LINES: u16 = 22;
 
 const SIGNAL_OPTIONS: [(&str, &str); 6] = [
     ("TERM", "-TERM"),
@@ -46,6 +57,7 @@ const SIGNAL_OPTIONS: [(&str, &str); 6] = [
 enum SortMode {
     Cpu,
     Mem,
+    Io,
     Pid,
     Name,
 }
@@ -54,7 +66,8 @@ impl SortMode {
     fn next(self) -> Self {
         match self {
             Self::Cpu => Self::Mem,
-            Self::Mem => Self::Pid,
+            Self::Mem => Self::Io,
+            Self::Io => Self::Pid,
             Self::Pid => Self::Name,
             Self::Name => Self::Cpu,
         }
@@ -64,10 +77,21 @@ impl SortMode {
         match self {
             Self::Cpu => Self::Name,
             Self::Mem => Self::Cpu,
-            Self::Pid => Self::Mem,
+            Self::Io => Self::Mem,
+            Self::Pid => Self::Io,
             Self::Name => Self::Pid,
         }
     }
+
+    fn from_config_str(s: &str) -> Self {
+        match s {
+            "mem" => Self::Mem,
+            "io" => Self::Io,
+            "pid" => Self::Pid,
+            "name" => Self::Name,
+            _ => Self::Cpu,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -88,6 +112,10 @@ struct ProcessRowData {
     mem_text: String,
     cpu_cell_per_core: String,
     cpu_cell_total: String,
+    disk_read_rate: f64,
+    disk_write_rate: f64,
+    disk_read_text: String,
+    disk_write_text: String,
 }
 
 impl ProcessRowData {
@@ -98,6 +126,10 @@ impl ProcessRowData {
             self.cpu_raw_percent
         }
     }
+
+    fn io_rate(&self) -> f64 {
+        self.disk_read_rate + self.disk_write_rate
+    }
 }
 
 enum InputMode {
@@ -105,6 +137,34 @@ enum InputMode {
     Filter,
 }
 
+#[derive(Clone, Copy, PartialEq)]
+enum FilterMode {
+    Substring,
+    CaseSensitive,
+    Regex,
+    Fuzzy,
+}
+
+impl FilterMode {
+    fn next(self) -> Self {
+        match self {
+            Self::Substring => Self::CaseSensitive,
+            Self::CaseSensitive => Self::Regex,
+            Self::Regex => Self::Fuzzy,
+            Self::Fuzzy => Self::Substring,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Substring => "sub",
+            Self::CaseSensitive => "case",
+            Self::Regex => "regex",
+            Self::Fuzzy => "fuzzy",
+        }
+    }
+}
+
 enum ModalState {
     Confirm {
         pid: u32,
@@ -115,12 +175,430 @@ enum ModalState {
     SignalPicker {
         selected: usize,
     },
+    Help {
+        scroll: u16,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct Config {
+    sort_mode: String,
+    sort_reverse: bool,
+    per_core: bool,
+    tree_mode: bool,
+    proc_lazy: bool,
+    tick_rate_ms: u64,
+    filter_query: String,
+    keys: KeyBindings,
+    theme: String,
+    custom_palette: Option<[[u8; 3]; 16]>,
+    hide_pseudo_fs: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            sort_mode: "cpu".to_string(),
+            sort_reverse: false,
+            per_core: true,
+            tree_mode: false,
+            proc_lazy: true,
+            tick_rate_ms: 1000,
+            filter_query: String::new(),
+            keys: KeyBindings::default(),
+            theme: "default".to_string(),
+            custom_palette: None,
+            hide_pseudo_fs: true,
+        }
+    }
+}
+
+/// A terminal 16-color palette, indexed the same way as the ANSI color
+/// codes (0=black .. 7=white, 8=bright black .. 15=bright white).
+type Palette = [[u8; 3]; 16];
+
+const DEFAULT_PALETTE: Palette = [
+    [0x00, 0x00, 0x00], [0xcc, 0x24, 0x1d], [0x98, 0x97, 0x1a], [0xd7, 0x99, 0x21],
+    [0x45, 0x85, 0x88], [0xb1, 0x62, 0x86], [0x68, 0x9d, 0x6a], [0xa8, 0x99, 0x84],
+    [0x92, 0x83, 0x74], [0xfb, 0x49, 0x34], [0xb8, 0xbb, 0x26], [0xfa, 0xbd, 0x2f],
+    [0x83, 0xa5, 0x98], [0xd3, 0x86, 0x9b], [0x8e, 0xc0, 0x7c], [0xfb, 0xf1, 0xc7],
+];
+
+const DRACULA_PALETTE: Palette = [
+    [0x21, 0x22, 0x2c], [0xff, 0x55, 0x55], [0x50, 0xfa, 0x7b], [0xf1, 0xfa, 0x8c],
+    [0xbd, 0x93, 0xf9], [0xff, 0x79, 0xc6], [0x8b, 0xe9, 0xfd], [0xf8, 0xf8, 0xf2],
+    [0x62, 0x72, 0xa4], [0xff, 0x6e, 0x6e], [0x69, 0xff, 0x94], [0xff, 0xff, 0xa5],
+    [0xd6, 0xac, 0xff], [0xff, 0x92, 0xdf], [0xa4, 0xff, 0xff], [0xff, 0xff, 0xff],
+];
+
+const SOLARIZED_PALETTE: Palette = [
+    [0x07, 0x36, 0x42], [0xdc, 0x32, 0x2f], [0x85, 0x99, 0x00], [0xb5, 0x89, 0x00],
+    [0x26, 0x8b, 0xd2], [0xd3, 0x36, 0x82], [0x2a, 0xa1, 0x98], [0xee, 0xe8, 0xd5],
+    [0x00, 0x2b, 0x36], [0xcb, 0x4b, 0x16], [0x58, 0x6e, 0x75], [0x65, 0x7b, 0x83],
+    [0x83, 0x94, 0x96], [0x6c, 0x71, 0xc4], [0x93, 0xa1, 0xa1], [0xfd, 0xf6, 0xe3],
+];
+
+fn builtin_palette(name: &str) -> Palette {
+    match name {
+        "dracula" => DRACULA_PALETTE,
+        "solarized" => SOLARIZED_PALETTE,
+        _ => DEFAULT_PALETTE,
+    }
+}
+
+fn resolve_palette(config: &Config) -> Palette {
+    if config.theme == "custom" {
+        config.custom_palette.unwrap_or(DEFAULT_PALETTE)
+    } else {
+        builtin_palette(&config.theme)
+    }
+}
+
+/// Best-effort check for whether the terminal will honor OSC 4 palette
+/// redefinition; conservative so we never corrupt output on a dumb terminal.
+fn terminal_supports_palette_osc() -> bool {
+    if std::env::var("TERM").map(|t| t == "dumb" || t == "linux").unwrap_or(true) {
+        return false;
+    }
+    std::env::var("COLORTERM").is_ok()
+        || std::env::var("TERM")
+            .map(|t| t.contains("256color") || t.contains("xterm") || t.contains("screen"))
+            .unwrap_or(false)
+}
+
+/// Emits OSC 4 sequences redefining the 16 basic color slots the UI draws
+/// from, so `ratatui::style::Color::Red` etc. render as the theme's colors.
+fn apply_palette_osc(palette: &Palette) {
+    let mut out = String::new();
+    for (i, [r, g, b]) in palette.iter().enumerate() {
+        out.push_str(&format!("\x1b]4;{i};rgb:{r:02x}/{g:02x}/{b:02x}\x07"));
+    }
+    print!("{out}");
+    let _ = io::stdout().flush();
+}
+
+/// Resets the terminal's color palette back to its own defaults (OSC 104
+/// with no arguments resets every slot).
+fn reset_palette_osc() {
+    print!("\x1b]104\x07");
+    let _ = io::stdout().flush();
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct KeyBindings {
+    cycle_sort: char,
+    reverse: char,
+    per_core: char,
+    tree: char,
+    lazy: char,
+    speed_up: char,
+    speed_down: char,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            cycle_sort: 'o',
+            reverse: 'r',
+            per_core: 'e',
+            tree: 'w',
+            lazy: 'l',
+            speed_up: '+',
+            speed_down: '-',
+        }
+    }
+}
+
+/// Message catalog: `message id -> display text`. Built once at startup for
+/// the active locale and carried on `App`, so lookups are a plain `HashMap`
+/// hit with no runtime parsing.
+type Catalog = HashMap<&'static str, &'static str>;
+
+/// English message/unit text; every other locale falls back to this for any
+/// id it doesn't override.
+const CATALOG_EN: &[(&str, &str)] = &[
+    ("status.per_core_on", "per-core on"),
+    ("status.per_core_off", "per-core off"),
+    ("status.tree_on", "tree on"),
+    ("status.tree_off", "tree off"),
+    ("status.lazy_on", "lazy on"),
+    ("status.lazy_off", "lazy off"),
+    ("status.basic_on", "basic on"),
+    ("status.basic_off", "basic off"),
+    ("status.pseudo_fs_hidden", "virtual filesystems hidden"),
+    ("status.pseudo_fs_shown", "virtual filesystems shown"),
+    ("status.temp_unit_f", "temp unit F"),
+    ("status.temp_unit_c", "temp unit C"),
+    ("status.follow_off", "follow off"),
+    ("status.follow_pid", "following pid {}"),
+    ("status.no_process_selected", "No process selected"),
+    ("status.followed_exited", "followed process {} exited"),
+    ("status.config_saved", "saved config to {}"),
+    ("status.config_save_failed", "failed to save config: {}"),
+    ("filter.off", "filter off"),
+    ("filter.active", "filter {} {}"),
+    ("filter.input_empty", "f {} _\u{25c4}"),
+    ("filter.input_active", "f {} {}\u{25c4}"),
+    ("title.cpu", "\u{250c}1 cpu\u{2510} \u{250c}menu\u{2510} \u{250c}preset *\u{2510}"),
+    ("title.mem", "\u{250c}2 mem\u{2510}"),
+    ("title.disks", "\u{250c}disks\u{2510}"),
+    ("title.net", "\u{250c}3 net\u{2510} {} {}"),
+    ("title.help", "help"),
+    ("title.confirm_signal", "confirm signal"),
+    ("title.select_signal", "select signal"),
+    ("title.sync", "sync auto zero <b eth0 n>"),
+    ("table.pid", "Pid"),
+    ("table.program", "Program:"),
+    ("table.command", "Command:"),
+    ("table.threads", "Threads:"),
+    ("table.user", "User:"),
+    ("table.mem", "MemB"),
+    ("table.cpu", "Cpu%"),
+    ("table.read", "Read/s"),
+    ("table.write", "Write/s"),
+    ("sensors.none", "No sensors detected"),
+    ("net.download", "download"),
+    ("net.upload", "upload"),
+    ("net.up_top", "up top"),
+    ("net.top", "Top:"),
+    ("net.total", "Total:"),
+    ("net.total_rx", "total rx"),
+    ("net.total_tx", "total tx"),
+    ("net.sync_status", "sort={} rev={} tree={} lazy={} upd={}ms draw={}ms"),
+    (
+        "help.body",
+        "navigation\n  \u{2191}/\u{2193} PgUp/PgDn Home/End  move selection\n  F  follow selected pid\n\n\
+         sorting\n  \u{2190}/\u{2192} / {}  cycle sort mode   {}  reverse\n  c cpu  m mem  i io  p pid  n name  jump to sort mode\n\n\
+         filter\n  / start filter   Tab cycle match mode   x clear filter\n\n\
+         signals\n  t  confirm SIGTERM   k  confirm SIGKILL   s  pick signal\n\n\
+         view\n  {}  per-core   {}  tree   {}  lazy   b  basic mode   u  \u{b0}C/\u{b0}F   v  virtual fs\n  {}/=  speed up   {}/_  slow down\n\n\
+         other\n  Ctrl+S  save config   q/Ctrl+C  quit\n\n\
+         Esc/?/q close this help",
+    ),
+    ("unit.b", "B"),
+    ("unit.k", "K"),
+    ("unit.m", "M"),
+    ("unit.g", "G"),
+    ("unit.kib", "KiB"),
+    ("unit.mib", "MiB"),
+    ("unit.gib", "GiB"),
+];
+
+/// Spanish overrides; any id not listed here falls back to [`CATALOG_EN`].
+const CATALOG_ES: &[(&str, &str)] = &[
+    ("status.per_core_on", "por nucleo activado"),
+    ("status.per_core_off", "por nucleo desactivado"),
+    ("status.tree_on", "arbol activado"),
+    ("status.tree_off", "arbol desactivado"),
+    ("status.lazy_on", "perezoso activado"),
+    ("status.lazy_off", "perezoso desactivado"),
+    ("status.basic_on", "basico activado"),
+    ("status.basic_off", "basico desactivado"),
+    ("status.pseudo_fs_hidden", "sistemas de archivos virtuales ocultos"),
+    ("status.pseudo_fs_shown", "sistemas de archivos virtuales visibles"),
+    ("status.temp_unit_f", "unidad de temperatura F"),
+    ("status.temp_unit_c", "unidad de temperatura C"),
+    ("status.follow_off", "seguimiento desactivado"),
+    ("status.follow_pid", "siguiendo pid {}"),
+    ("status.no_process_selected", "Ningun proceso seleccionado"),
+    ("status.followed_exited", "el proceso seguido {} termino"),
+    ("status.config_saved", "configuracion guardada en {}"),
+    ("status.config_save_failed", "error al guardar la configuracion: {}"),
+    ("filter.off", "filtro apagado"),
+    ("filter.active", "filtro {} {}"),
+    ("title.sync", "sincr auto cero <b eth0 n>"),
+    ("table.pid", "Pid"),
+    ("table.program", "Programa:"),
+    ("table.command", "Comando:"),
+    ("table.threads", "Hilos:"),
+    ("table.user", "Usuario:"),
+    ("table.mem", "MemB"),
+    ("table.cpu", "Cpu%"),
+    ("table.read", "Lect/s"),
+    ("table.write", "Escr/s"),
+    ("sensors.none", "No se detectaron sensores"),
+    ("net.download", "descarga"),
+    ("net.upload", "subida"),
+    ("net.up_top", "pico"),
+    ("net.top", "Pico:"),
+    ("net.total", "Total:"),
+    ("net.total_rx", "total rx"),
+    ("net.total_tx", "total tx"),
+    ("net.sync_status", "orden={} inv={} arbol={} perezoso={} act={}ms dib={}ms"),
+    (
+        "help.body",
+        "navegacion\n  \u{2191}/\u{2193} RePag/AvPag Inicio/Fin  mover seleccion\n  F  seguir pid seleccionado\n\n\
+         orden\n  \u{2190}/\u{2192} / {}  ciclar orden   {}  invertir\n  c cpu  m mem  i io  p pid  n name  saltar a modo de orden\n\n\
+         filtro\n  / iniciar filtro   Tab ciclar modo de coincidencia   x limpiar filtro\n\n\
+         senales\n  t  confirmar SIGTERM   k  confirmar SIGKILL   s  elegir senal\n\n\
+         vista\n  {}  por nucleo   {}  arbol   {}  perezoso   b  modo basico   u  \u{b0}C/\u{b0}F   v  fs virtual\n  {}/=  acelerar   {}/_  ralentizar\n\n\
+         otros\n  Ctrl+S  guardar config   q/Ctrl+C  salir\n\n\
+         Esc/?/q cerrar esta ayuda",
+    ),
+    ("unit.b", "B"),
+    ("unit.k", "K"),
+    ("unit.m", "M"),
+    ("unit.g", "G"),
+    ("unit.kib", "KiB"),
+    ("unit.mib", "MiB"),
+    ("unit.gib", "GiB"),
+];
+
+/// French overrides; any id not listed here falls back to [`CATALOG_EN`].
+const CATALOG_FR: &[(&str, &str)] = &[
+    ("status.per_core_on", "par coeur actif"),
+    ("status.per_core_off", "par coeur inactif"),
+    ("status.tree_on", "arbre actif"),
+    ("status.tree_off", "arbre inactif"),
+    ("status.lazy_on", "paresseux actif"),
+    ("status.lazy_off", "paresseux inactif"),
+    ("status.basic_on", "basique actif"),
+    ("status.basic_off", "basique inactif"),
+    ("status.pseudo_fs_hidden", "systemes de fichiers virtuels masques"),
+    ("status.pseudo_fs_shown", "systemes de fichiers virtuels affiches"),
+    ("status.temp_unit_f", "unite de temperature F"),
+    ("status.temp_unit_c", "unite de temperature C"),
+    ("status.follow_off", "suivi desactive"),
+    ("status.follow_pid", "suivi du pid {}"),
+    ("status.no_process_selected", "Aucun processus selectionne"),
+    ("status.followed_exited", "le processus suivi {} a quitte"),
+    ("status.config_saved", "configuration enregistree dans {}"),
+    ("status.config_save_failed", "echec de l'enregistrement de la configuration : {}"),
+    ("filter.off", "filtre desactive"),
+    ("filter.active", "filtre {} {}"),
+    ("title.sync", "sync auto zero <b eth0 n>"),
+    ("table.pid", "Pid"),
+    ("table.program", "Programme:"),
+    ("table.command", "Commande:"),
+    ("table.threads", "Threads:"),
+    ("table.user", "Utilisateur:"),
+    ("table.mem", "MemO"),
+    ("table.cpu", "Cpu%"),
+    ("table.read", "Lec/s"),
+    ("table.write", "Ecr/s"),
+    ("sensors.none", "Aucun capteur detecte"),
+    ("net.download", "telechargement"),
+    ("net.upload", "envoi"),
+    ("net.up_top", "pic"),
+    ("net.top", "Pic:"),
+    ("net.total", "Total:"),
+    ("net.total_rx", "total rx"),
+    ("net.total_tx", "total tx"),
+    ("net.sync_status", "tri={} inv={} arbre={} paresseux={} maj={}ms dess={}ms"),
+    (
+        "help.body",
+        "navigation\n  \u{2191}/\u{2193} PgUp/PgDn Home/End  deplacer la selection\n  F  suivre le pid selectionne\n\n\
+         tri\n  \u{2190}/\u{2192} / {}  changer le mode de tri   {}  inverser\n  c cpu  m mem  i io  p pid  n name  aller au mode de tri\n\n\
+         filtre\n  / demarrer le filtre   Tab changer le mode de correspondance   x effacer le filtre\n\n\
+         signaux\n  t  confirmer SIGTERM   k  confirmer SIGKILL   s  choisir un signal\n\n\
+         vue\n  {}  par coeur   {}  arbre   {}  paresseux   b  mode basique   u  \u{b0}C/\u{b0}F   v  fs virtuel\n  {}/=  accelerer   {}/_  ralentir\n\n\
+         autre\n  Ctrl+S  enregistrer la config   q/Ctrl+C  quitter\n\n\
+         Esc/?/q fermer cette aide",
+    ),
+    ("unit.b", "o"),
+    ("unit.k", "Ko"),
+    ("unit.m", "Mo"),
+    ("unit.g", "Go"),
+    ("unit.kib", "Kio"),
+    ("unit.mib", "Mio"),
+    ("unit.gib", "Gio"),
+];
+
+/// Builds the active catalog: the English baseline with any entries the
+/// locale overrides layered on top, so an incomplete translation still shows
+/// English rather than a missing string.
+fn build_catalog(locale: &str) -> Catalog {
+    let mut catalog: Catalog = CATALOG_EN.iter().copied().collect();
+    let overrides: &[(&str, &str)] = match locale {
+        "es" => CATALOG_ES,
+        "fr" => CATALOG_FR,
+        _ => &[],
+    };
+    for (id, text) in overrides {
+        catalog.insert(id, text);
+    }
+    catalog
+}
+
+/// Picks the active locale: `--lang` wins, then `$LC_MESSAGES`, then `$LANG`,
+/// then the compiled-in English default. Strips any `_COUNTRY`/`.ENCODING`
+/// suffix (e.g. `fr_FR.UTF-8` -> `fr`).
+fn detect_locale(cli_lang: Option<&str>) -> String {
+    let raw = cli_lang
+        .map(str::to_string)
+        .or_else(|| std::env::var("LC_MESSAGES").ok())
+        .or_else(|| std::env::var("LANG").ok())
+        .unwrap_or_else(|| "en".to_string());
+    raw.split(['_', '.'])
+        .next()
+        .unwrap_or("en")
+        .to_lowercase()
+}
+
+fn tr(catalog: &Catalog, id: &'static str) -> &'static str {
+    catalog.get(id).copied().unwrap_or(id)
+}
+
+fn tr1(catalog: &Catalog, id: &'static str, a: &str) -> String {
+    tr(catalog, id).replacen("{}", a, 1)
+}
+
+fn tr2(catalog: &Catalog, id: &'static str, a: &str, b: &str) -> String {
+    tr(catalog, id).replacen("{}", a, 1).replacen("{}", b, 1)
+}
+
+/// Like [`tr1`]/[`tr2`] but for templates with more than two `{}` slots,
+/// filled left to right.
+fn trn(catalog: &Catalog, id: &'static str, args: &[&str]) -> String {
+    let mut out = tr(catalog, id).to_string();
+    for arg in args {
+        out = out.replacen("{}", arg, 1);
+    }
+    out
+}
+
+fn default_config_path() -> PathBuf {
+    let base = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| std::env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|_| PathBuf::from("."));
+    base.join("utop").join("config.toml")
+}
+
+fn load_config(path: &Path) -> Config {
+    match fs::read_to_string(path) {
+        Ok(text) => toml::from_str(&text).unwrap_or_default(),
+        Err(_) => {
+            let config = Config::default();
+            if let Some(parent) = path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            if let Ok(text) = toml::to_string_pretty(&config) {
+                let _ = fs::write(path, text);
+            }
+            config
+        }
+    }
+}
+
+fn save_config(path: &Path, config: &Config) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let text =
+        toml::to_string_pretty(config).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    fs::write(path, text)
 }
 
 struct App {
     system: System,
     disks: Disks,
     networks: Networks,
+    components: Components,
+    sensor_history: HashMap<String, VecDeque<f32>>,
+    temp_fahrenheit: bool,
     last_tick: Instant,
     tick_rate: Duration,
     cpu_count: usize,
@@ -128,14 +606,28 @@ struct App {
     core_usages: Vec<f32>,
     all_process_rows: Vec<ProcessRowData>,
     process_rows: Vec<ProcessRowData>,
+    prev_disk_io: HashMap<u32, (u64, u64)>,
+    prev_disk_bytes: HashMap<String, (u64, u64)>,
+    disk_rates: HashMap<String, (f64, f64)>,
+    disk_rate_history: HashMap<String, (VecDeque<u64>, VecDeque<u64>)>,
+    mount_stats: HashMap<PathBuf, (String, u64, u64)>,
+    hide_pseudo_fs: bool,
+    theme: String,
+    custom_palette: Option<Palette>,
+    catalog: Catalog,
     selected_process: usize,
     process_scroll: usize,
+    follow_pid: Option<u32>,
     sort_mode: SortMode,
     sort_reverse: bool,
     per_core: bool,
     tree_mode: bool,
     proc_lazy: bool,
     filter_query: String,
+    filter_cursor: usize,
+    filter_mode: FilterMode,
+    filter_regex: Option<Result<Regex, regex::Error>>,
+    filter_invalid: bool,
     input_mode: InputMode,
     net_iface: String,
     net_rx_rate: f64,
@@ -150,6 +642,8 @@ struct App {
     last_disk_refresh: Instant,
     last_process_refresh: Instant,
     last_ip_refresh: Instant,
+    last_net_sample: Option<Instant>,
+    metrics: Option<SharedMetrics>,
     modal: Option<ModalState>,
     status_msg: String,
     dirty: bool,
@@ -161,29 +655,55 @@ struct App {
     perf_update_ms_ema: f64,
     perf_draw_ms_ema: f64,
     pending_scroll: i32,
+    basic_mode: bool,
+    config_path: PathBuf,
+    keys: KeyBindings,
 }
 
 impl App {
-    fn new() -> Self {
+    fn new(
+        basic_mode: bool,
+        config_path: PathBuf,
+        config: &Config,
+        metrics: Option<SharedMetrics>,
+        locale: String,
+    ) -> Self {
         let mut app = Self {
             system: System::new_all(),
             disks: Disks::new_with_refreshed_list(),
             networks: Networks::new_with_refreshed_list(),
+            components: Components::new_with_refreshed_list(),
+            sensor_history: HashMap::new(),
+            temp_fahrenheit: false,
             last_tick: Instant::now(),
-            tick_rate: Duration::from_millis(1000),
+            tick_rate: Duration::from_millis(config.tick_rate_ms.clamp(MIN_TICK_MS, MAX_TICK_MS)),
             cpu_count: 1,
             cpu_history: VecDeque::with_capacity(MAX_CPU_HISTORY),
             core_usages: Vec::new(),
             all_process_rows: Vec::new(),
             process_rows: Vec::new(),
+            prev_disk_io: HashMap::new(),
+            prev_disk_bytes: HashMap::new(),
+            disk_rates: HashMap::new(),
+            disk_rate_history: HashMap::new(),
+            mount_stats: HashMap::new(),
+            hide_pseudo_fs: config.hide_pseudo_fs,
+            theme: config.theme.clone(),
+            custom_palette: config.custom_palette,
+            catalog: build_catalog(&locale),
             selected_process: 0,
             process_scroll: 0,
-            sort_mode: SortMode::Cpu,
-            sort_reverse: false,
-            per_core: true,
-            tree_mode: false,
-            proc_lazy: true,
-            filter_query: String::new(),
+            follow_pid: None,
+            sort_mode: SortMode::from_config_str(&config.sort_mode),
+            sort_reverse: config.sort_reverse,
+            per_core: config.per_core,
+            tree_mode: config.tree_mode,
+            proc_lazy: config.proc_lazy,
+            filter_query: config.filter_query.clone(),
+            filter_cursor: config.filter_query.chars().count(),
+            filter_mode: FilterMode::Substring,
+            filter_regex: None,
+            filter_invalid: false,
             input_mode: InputMode::Normal,
             net_iface: "-".to_string(),
             net_rx_rate: 0.0,
@@ -198,6 +718,8 @@ impl App {
             last_disk_refresh: Instant::now(),
             last_process_refresh: Instant::now(),
             last_ip_refresh: Instant::now() - Duration::from_secs(30),
+            last_net_sample: None,
+            metrics,
             modal: None,
             status_msg: String::new(),
             dirty: true,
@@ -213,6 +735,9 @@ impl App {
             perf_update_ms_ema: 0.0,
             perf_draw_ms_ema: 0.0,
             pending_scroll: 0,
+            basic_mode,
+            config_path,
+            keys: config.keys.clone(),
         };
         app.update();
         app
@@ -226,16 +751,19 @@ impl App {
         let recent_input = self.last_input.elapsed() < Duration::from_millis(300);
         let max_staleness = Duration::from_secs(5);
         let mut process_refreshed = false;
+        let mut process_refresh_interval_secs = 0.0;
         if self.all_process_rows.is_empty()
             || (self.last_process_refresh.elapsed() >= Duration::from_millis(process_interval)
                 && (!recent_input || self.last_process_refresh.elapsed() >= max_staleness))
         {
+            process_refresh_interval_secs = self.last_process_refresh.elapsed().as_secs_f64().max(0.001);
             self.system.refresh_processes_specifics(
                 ProcessesToUpdate::All,
                 true,
                 ProcessRefreshKind::nothing()
                     .with_cpu()
                     .with_memory()
+                    .with_disk_usage()
                     .with_cmd(UpdateKind::OnlyIfNotSet)
                     .without_tasks(),
             );
@@ -243,7 +771,59 @@ impl App {
             self.last_process_refresh = Instant::now();
         }
         if self.last_disk_refresh.elapsed() >= Duration::from_secs(5) {
+            let disk_interval_secs = self.last_disk_refresh.elapsed().as_secs_f64().max(0.001);
+            let next_disk_bytes = read_diskstats();
+            let mut disk_rates = HashMap::with_capacity(next_disk_bytes.len());
+            for (name, (read_bytes, written_bytes)) in &next_disk_bytes {
+                if let Some((prev_read, prev_written)) = self.prev_disk_bytes.get(name) {
+                    let read_rate =
+                        read_bytes.saturating_sub(*prev_read) as f64 / disk_interval_secs;
+                    let write_rate =
+                        written_bytes.saturating_sub(*prev_written) as f64 / disk_interval_secs;
+                    disk_rates.insert(name.clone(), (read_rate, write_rate));
+                    let history = self.disk_rate_history.entry(name.clone()).or_default();
+                    history.0.push_back(read_rate as u64);
+                    history.1.push_back(write_rate as u64);
+                    while history.0.len() > MAX_DISK_HISTORY {
+                        history.0.pop_front();
+                    }
+                    while history.1.len() > MAX_DISK_HISTORY {
+                        history.1.pop_front();
+                    }
+                }
+            }
+            self.disk_rates = disk_rates;
+            self.prev_disk_bytes = next_disk_bytes;
             self.disks.refresh(false);
+            let fs_types = read_mount_fs_types();
+            self.mount_stats = self
+                .disks
+                .list()
+                .iter()
+                .map(|disk| {
+                    let mount = disk.mount_point().to_path_buf();
+                    let fs_type = fs_types
+                        .get(&mount)
+                        .cloned()
+                        .unwrap_or_else(|| "?".to_string());
+                    let (inodes_total, inodes_free) =
+                        statvfs_inodes(&mount).unwrap_or((0, 0));
+                    (mount, (fs_type, inodes_total, inodes_free))
+                })
+                .collect();
+            self.components.refresh(false);
+            for component in self.components.list() {
+                let history = self
+                    .sensor_history
+                    .entry(component.label().to_string())
+                    .or_insert_with(|| VecDeque::with_capacity(MAX_SENSOR_HISTORY));
+                if let Some(temp) = component.temperature() {
+                    history.push_back(temp);
+                    while history.len() > MAX_SENSOR_HISTORY {
+                        history.pop_front();
+                    }
+                }
+            }
             self.last_disk_refresh = Instant::now();
         }
         self.networks.refresh(true);
@@ -270,6 +850,8 @@ impl App {
 
         if process_refreshed {
             let total_mem = self.system.total_memory().max(1) as f64;
+            let mut next_disk_io: HashMap<u32, (u64, u64)> =
+                HashMap::with_capacity(self.system.processes().len());
             self.all_process_rows = self
                 .system
                 .processes()
@@ -282,9 +864,24 @@ impl App {
                             .clamp(0.0, (self.cpu_count as f32 * 100.0).max(100.0));
                     let cpu_total_percent = (cpu_usage / self.cpu_count as f32).clamp(0.0, 100.0);
                     let mem_bytes = p.memory();
+                    let pid_num = p.pid().as_u32();
+                    let disk_usage = p.disk_usage();
+                    let (disk_read_rate, disk_write_rate) =
+                        if self.prev_disk_io.contains_key(&pid_num) {
+                            (
+                                disk_usage.read_bytes as f64 / process_refresh_interval_secs,
+                                disk_usage.written_bytes as f64 / process_refresh_interval_secs,
+                            )
+                        } else {
+                            (0.0, 0.0)
+                        };
+                    next_disk_io.insert(
+                        pid_num,
+                        (disk_usage.total_read_bytes, disk_usage.total_written_bytes),
+                    );
                     ProcessRowData {
                         pid: p.pid().to_string(),
-                        pid_num: p.pid().as_u32(),
+                        pid_num,
                         parent_pid: p.parent().map(|pp| pp.as_u32()),
                         name_lc: name.to_lowercase(),
                         name: name.clone(),
@@ -305,7 +902,7 @@ impl App {
                         tree_prefix: String::new(),
                         display_name: name.clone(),
                         threads_text: threads.to_string(),
-                        mem_text: format!("{:>6}", human_bytes(mem_bytes)),
+                        mem_text: format!("{:>6}", human_bytes(&self.catalog, mem_bytes)),
                         cpu_cell_per_core: format!(
                             "{} {:>4.1}",
                             bar(cpu_total_percent, 5),
@@ -316,9 +913,14 @@ impl App {
                             bar((cpu_usage / self.cpu_count as f32).clamp(0.0, 100.0), 5),
                             cpu_usage
                         ),
+                        disk_read_rate,
+                        disk_write_rate,
+                        disk_read_text: format!("{:>9}/s", human_rate(&self.catalog, disk_read_rate)),
+                        disk_write_text: format!("{:>9}/s", human_rate(&self.catalog, disk_write_rate)),
                     }
                 })
                 .collect();
+            self.prev_disk_io = next_disk_io;
             for row in &mut self.all_process_rows {
                 if row.command.is_empty() {
                     row.command = row.name.clone();
@@ -337,9 +939,23 @@ impl App {
             .or_else(|| self.networks.iter().next());
         if let Some((name, data)) = selected {
             self.net_iface = name.clone();
-            let elapsed = self.last_tick.elapsed().as_secs_f64().max(0.001);
-            self.net_rx_rate = data.received() as f64 / elapsed;
-            self.net_tx_rate = data.transmitted() as f64 / elapsed;
+            let now = Instant::now();
+            match self.last_net_sample {
+                None => {
+                    self.net_rx_rate = 0.0;
+                    self.net_tx_rate = 0.0;
+                }
+                Some(prev) => {
+                    let elapsed = now.duration_since(prev).as_secs_f64();
+                    if elapsed >= 0.001 {
+                        self.net_rx_rate =
+                            ema(self.net_rx_rate, data.received() as f64 / elapsed, 0.2);
+                        self.net_tx_rate =
+                            ema(self.net_tx_rate, data.transmitted() as f64 / elapsed, 0.2);
+                    }
+                }
+            }
+            self.last_net_sample = Some(now);
             self.net_rx_top = self.net_rx_top.max(self.net_rx_rate);
             self.net_tx_top = self.net_tx_top.max(self.net_tx_rate);
             self.net_rx_total = data.total_received();
@@ -359,24 +975,51 @@ impl App {
             }
             self.last_ip_refresh = Instant::now();
         }
+        if let Some(metrics) = &self.metrics {
+            let mut snapshot = metrics.lock().unwrap();
+            snapshot.cpu_usage = self.system.global_cpu_usage().clamp(0.0, 100.0);
+            snapshot.mem_used = self.system.used_memory();
+            snapshot.mem_total = self.system.total_memory();
+            snapshot.net_iface = self.net_iface.clone();
+            snapshot.net_rx_rate = self.net_rx_rate;
+            snapshot.net_tx_rate = self.net_tx_rate;
+            snapshot.disks = self
+                .disks
+                .list()
+                .iter()
+                .map(|disk| {
+                    let total = disk.total_space();
+                    let avail = disk.available_space();
+                    (
+                        friendly_mount(disk.mount_point()),
+                        total.saturating_sub(avail),
+                        total,
+                    )
+                })
+                .collect();
+        }
         self.dirty = true;
     }
 
     fn rebuild_process_rows(&mut self) {
-        let selected_pid = self
-            .process_rows
-            .get(self.selected_process)
-            .map(|p| p.pid_num);
+        let selected_pid = self.follow_pid.or_else(|| {
+            self.process_rows
+                .get(self.selected_process)
+                .map(|p| p.pid_num)
+        });
+
+        self.recompile_filter();
+        if self.filter_invalid {
+            // Invalid regex: keep whatever row set we already had instead of
+            // clearing the table out from under the user mid-keystroke.
+            self.dirty = true;
+            return;
+        }
 
-        let query = self.filter_query.to_lowercase();
         let mut rows: Vec<ProcessRowData> = self
             .all_process_rows
             .iter()
-            .filter(|row| {
-                query.is_empty()
-                    || row.name_lc.contains(&query)
-                    || row.pid.contains(&self.filter_query)
-            })
+            .filter(|row| self.row_matches(row))
             .cloned()
             .collect();
 
@@ -392,6 +1035,7 @@ impl App {
                     .mem_percent
                     .total_cmp(&a.mem_percent)
                     .then_with(|| b.cpu_total_percent.total_cmp(&a.cpu_total_percent)),
+                SortMode::Io => b.io_rate().total_cmp(&a.io_rate()),
                 SortMode::Pid => a.pid_num.cmp(&b.pid_num),
                 SortMode::Name => a.name.cmp(&b.name),
             });
@@ -414,9 +1058,15 @@ impl App {
         if let Some(pid) = selected_pid {
             if let Some(idx) = self.process_rows.iter().position(|r| r.pid_num == pid) {
                 self.selected_process = idx;
+            } else if self.follow_pid == Some(pid) {
+                self.follow_pid = None;
+                self.status_msg = tr1(&self.catalog, "status.followed_exited", &pid.to_string());
             }
         }
         self.clamp_selection();
+        if self.follow_pid.is_some() {
+            self.align_scroll_to_selection(self.last_table_visible_rows);
+        }
         self.dirty = true;
     }
 
@@ -528,42 +1178,189 @@ impl App {
 
     fn toggle_per_core(&mut self) {
         self.per_core = !self.per_core;
-        self.status_msg = format!("per-core {}", if self.per_core { "on" } else { "off" });
+        self.status_msg = tr(
+            &self.catalog,
+            if self.per_core { "status.per_core_on" } else { "status.per_core_off" },
+        )
+        .to_string();
         self.rebuild_process_rows();
     }
 
     fn toggle_tree_mode(&mut self) {
         self.tree_mode = !self.tree_mode;
-        self.status_msg = format!("tree {}", if self.tree_mode { "on" } else { "off" });
+        self.status_msg = tr(
+            &self.catalog,
+            if self.tree_mode { "status.tree_on" } else { "status.tree_off" },
+        )
+        .to_string();
         self.rebuild_process_rows();
     }
 
+    fn save_config(&mut self) {
+        let config = Config {
+            sort_mode: sort_name(self.sort_mode).to_string(),
+            sort_reverse: self.sort_reverse,
+            per_core: self.per_core,
+            tree_mode: self.tree_mode,
+            proc_lazy: self.proc_lazy,
+            tick_rate_ms: self.tick_rate.as_millis() as u64,
+            filter_query: self.filter_query.clone(),
+            keys: self.keys.clone(),
+            theme: self.theme.clone(),
+            custom_palette: self.custom_palette,
+            hide_pseudo_fs: self.hide_pseudo_fs,
+        };
+        self.status_msg = match save_config(&self.config_path, &config) {
+            Ok(()) => tr1(&self.catalog, "status.config_saved", &self.config_path.display().to_string()),
+            Err(e) => tr1(&self.catalog, "status.config_save_failed", &e.to_string()),
+        };
+        self.dirty = true;
+    }
+
+    fn toggle_temp_unit(&mut self) {
+        self.temp_fahrenheit = !self.temp_fahrenheit;
+        self.status_msg = tr(
+            &self.catalog,
+            if self.temp_fahrenheit { "status.temp_unit_f" } else { "status.temp_unit_c" },
+        )
+        .to_string();
+        self.dirty = true;
+    }
+
+    fn toggle_basic_mode(&mut self) {
+        self.basic_mode = !self.basic_mode;
+        self.status_msg = tr(
+            &self.catalog,
+            if self.basic_mode { "status.basic_on" } else { "status.basic_off" },
+        )
+        .to_string();
+        self.dirty = true;
+    }
+
+    fn toggle_hide_pseudo_fs(&mut self) {
+        self.hide_pseudo_fs = !self.hide_pseudo_fs;
+        self.status_msg = tr(
+            &self.catalog,
+            if self.hide_pseudo_fs { "status.pseudo_fs_hidden" } else { "status.pseudo_fs_shown" },
+        )
+        .to_string();
+        self.dirty = true;
+    }
+
+    fn toggle_follow(&mut self) {
+        if self.follow_pid.take().is_some() {
+            self.status_msg = tr(&self.catalog, "status.follow_off").to_string();
+        } else if let Some(row) = self.selected_process_row() {
+            let pid_num = row.pid_num;
+            self.follow_pid = Some(pid_num);
+            self.status_msg = tr1(&self.catalog, "status.follow_pid", &pid_num.to_string());
+        } else {
+            self.status_msg = tr(&self.catalog, "status.no_process_selected").to_string();
+        }
+        self.dirty = true;
+    }
+
     fn toggle_proc_lazy(&mut self) {
         self.proc_lazy = !self.proc_lazy;
-        self.status_msg = format!("lazy {}", if self.proc_lazy { "on" } else { "off" });
+        self.status_msg = tr(
+            &self.catalog,
+            if self.proc_lazy { "status.lazy_on" } else { "status.lazy_off" },
+        )
+        .to_string();
         self.dirty = true;
     }
 
     fn start_filter_input(&mut self) {
         self.input_mode = InputMode::Filter;
+        self.filter_cursor = self.filter_query.chars().count();
         self.dirty = true;
     }
 
     fn clear_filter(&mut self) {
         self.filter_query.clear();
+        self.filter_cursor = 0;
+        self.rebuild_process_rows();
+    }
+
+    fn recompile_filter(&mut self) {
+        self.filter_regex = if self.filter_query.is_empty() || self.filter_mode != FilterMode::Regex
+        {
+            None
+        } else {
+            Some(Regex::new(&self.filter_query))
+        };
+        self.filter_invalid = matches!(self.filter_regex, Some(Err(_)));
+    }
+
+    fn row_matches(&self, row: &ProcessRowData) -> bool {
+        if self.filter_query.is_empty() {
+            return true;
+        }
+        match self.filter_mode {
+            FilterMode::Substring => {
+                let query = self.filter_query.to_lowercase();
+                row.name_lc.contains(&query)
+                    || row.pid.contains(&self.filter_query)
+                    || row.command.to_lowercase().contains(&query)
+                    || row.user.to_lowercase().contains(&query)
+            }
+            FilterMode::CaseSensitive => {
+                row.name.contains(&self.filter_query)
+                    || row.pid.contains(&self.filter_query)
+                    || row.command.contains(&self.filter_query)
+                    || row.user.contains(&self.filter_query)
+            }
+            FilterMode::Regex => match &self.filter_regex {
+                Some(Ok(re)) => {
+                    re.is_match(&row.name)
+                        || re.is_match(&row.pid)
+                        || re.is_match(&row.command)
+                        || re.is_match(&row.user)
+                }
+                _ => true,
+            },
+            FilterMode::Fuzzy => {
+                let query = self.filter_query.to_lowercase();
+                fuzzy_subsequence_match(&row.name_lc, &query)
+                    || fuzzy_subsequence_match(&row.command.to_lowercase(), &query)
+                    || fuzzy_subsequence_match(&row.user.to_lowercase(), &query)
+            }
+        }
+    }
+
+    fn cycle_filter_mode(&mut self) {
+        self.filter_mode = self.filter_mode.next();
         self.rebuild_process_rows();
     }
 
     fn handle_filter_key(&mut self, code: KeyCode) {
         match code {
             KeyCode::Esc | KeyCode::Enter => self.input_mode = InputMode::Normal,
+            KeyCode::Tab => self.cycle_filter_mode(),
+            KeyCode::Left => self.filter_cursor = self.filter_cursor.saturating_sub(1),
+            KeyCode::Right => {
+                self.filter_cursor = (self.filter_cursor + 1).min(self.filter_query.chars().count());
+            }
+            KeyCode::Home => self.filter_cursor = 0,
+            KeyCode::End => self.filter_cursor = self.filter_query.chars().count(),
             KeyCode::Backspace => {
-                self.filter_query.pop();
-                self.rebuild_process_rows();
+                if self.filter_cursor > 0 {
+                    let idx = self.filter_cursor - 1;
+                    remove_char_at(&mut self.filter_query, idx);
+                    self.filter_cursor = idx;
+                    self.rebuild_process_rows();
+                }
+            }
+            KeyCode::Delete => {
+                if self.filter_cursor < self.filter_query.chars().count() {
+                    remove_char_at(&mut self.filter_query, self.filter_cursor);
+                    self.rebuild_process_rows();
+                }
             }
             KeyCode::Char(c) => {
                 if !c.is_control() {
-                    self.filter_query.push(c);
+                    insert_char_at(&mut self.filter_query, self.filter_cursor, c);
+                    self.filter_cursor += 1;
                     self.rebuild_process_rows();
                 }
             }
@@ -585,7 +1382,7 @@ impl App {
                 signal_arg,
             });
         } else {
-            self.status_msg = "No process selected".to_string();
+            self.status_msg = tr(&self.catalog, "status.no_process_selected").to_string();
         }
         self.dirty = true;
     }
@@ -645,6 +1442,32 @@ impl App {
                         self.modal = Some(ModalState::SignalPicker { selected });
                     }
                 },
+                ModalState::Help { scroll } => match code {
+                    KeyCode::Esc | KeyCode::Char('?') | KeyCode::Char('q') => {}
+                    KeyCode::Down => {
+                        self.modal = Some(ModalState::Help {
+                            scroll: scroll.saturating_add(1).min(HELP_TEXT_LINES),
+                        });
+                    }
+                    KeyCode::Up => {
+                        self.modal = Some(ModalState::Help {
+                            scroll: scroll.saturating_sub(1),
+                        });
+                    }
+                    KeyCode::PageDown => {
+                        self.modal = Some(ModalState::Help {
+                            scroll: scroll.saturating_add(10).min(HELP_TEXT_LINES),
+                        });
+                    }
+                    KeyCode::PageUp => {
+                        self.modal = Some(ModalState::Help {
+                            scroll: scroll.saturating_sub(10),
+                        });
+                    }
+                    _ => {
+                        self.modal = Some(ModalState::Help { scroll });
+                    }
+                },
             }
         }
         self.dirty = true;
@@ -652,19 +1475,68 @@ impl App {
 }
 
 fn main() -> AppResult<()> {
+    let args: Vec<String> = std::env::args().collect();
+    let basic_mode = args.iter().any(|arg| arg == "--basic");
+    let config_path = args
+        .iter()
+        .position(|arg| arg == "--config")
+        .and_then(|i| args.get(i + 1))
+        .map(PathBuf::from)
+        .unwrap_or_else(default_config_path);
+    let mut config = load_config(&config_path);
+    if let Some(theme) = args
+        .iter()
+        .position(|arg| arg == "--theme")
+        .and_then(|i| args.get(i + 1))
+    {
+        config.theme = theme.clone();
+    }
+
+    let listen_addr = args
+        .iter()
+        .position(|arg| arg == "--listen")
+        .and_then(|i| args.get(i + 1))
+        .cloned();
+    let metrics: Option<SharedMetrics> = listen_addr.and_then(|addr| {
+        let shared = Arc::new(Mutex::new(MetricsSnapshot::default()));
+        match spawn_metrics_server(&addr, shared.clone()) {
+            Ok(()) => Some(shared),
+            Err(e) => {
+                eprintln!("failed to start metrics server on {addr}: {e}");
+                None
+            }
+        }
+    });
+
+    let cli_lang = args
+        .iter()
+        .position(|arg| arg == "--lang")
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str);
+    let locale = detect_locale(cli_lang);
+
+    let palette_applied = terminal_supports_palette_osc();
+    if palette_applied {
+        apply_palette_osc(&resolve_palette(&config));
+    }
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new();
+    let mut app = App::new(basic_mode, config_path, &config, metrics, locale);
     let res = run_app(&mut terminal, &mut app);
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
 
+    if palette_applied {
+        reset_palette_osc();
+    }
+
     if let Err(err) = res {
         eprintln!("{err:?}");
     }
@@ -714,6 +1586,11 @@ where
                                     {
                                         return Ok(());
                                     }
+                                    KeyCode::Char('s')
+                                        if key.modifiers.contains(KeyModifiers::CONTROL) =>
+                                    {
+                                        app.save_config()
+                                    }
                                     KeyCode::Down => app.queue_scroll(1),
                                     KeyCode::Up => app.queue_scroll(-1),
                                     KeyCode::PageDown => app.queue_scroll(page_size as i32),
@@ -737,23 +1614,43 @@ where
                                     KeyCode::Char('s') => {
                                         app.modal = Some(ModalState::SignalPicker { selected: 0 })
                                     }
-                                    KeyCode::Char('o') => app.cycle_sort_mode(),
+                                    KeyCode::Char(c) if c == app.keys.cycle_sort => {
+                                        app.cycle_sort_mode()
+                                    }
                                     KeyCode::Left => app.cycle_sort_mode_prev(),
                                     KeyCode::Right => app.cycle_sort_mode(),
-                                    KeyCode::Char('r') => app.toggle_reverse(),
-                                    KeyCode::Char('e') => app.toggle_per_core(),
-                                    KeyCode::Char('w') => app.toggle_tree_mode(),
-                                    KeyCode::Char('l') => app.toggle_proc_lazy(),
+                                    KeyCode::Char(c) if c == app.keys.reverse => {
+                                        app.toggle_reverse()
+                                    }
+                                    KeyCode::Char(c) if c == app.keys.per_core => {
+                                        app.toggle_per_core()
+                                    }
+                                    KeyCode::Char(c) if c == app.keys.tree => {
+                                        app.toggle_tree_mode()
+                                    }
+                                    KeyCode::Char(c) if c == app.keys.lazy => {
+                                        app.toggle_proc_lazy()
+                                    }
+                                    KeyCode::Char('?') => {
+                                        app.modal = Some(ModalState::Help { scroll: 0 })
+                                    }
+                                    KeyCode::Char('F') => app.toggle_follow(),
+                                    KeyCode::Char('b') => app.toggle_basic_mode(),
+                                    KeyCode::Char('v') => app.toggle_hide_pseudo_fs(),
+                                    KeyCode::Char('u') => app.toggle_temp_unit(),
                                     KeyCode::Char('c') => app.set_sort_mode(SortMode::Cpu),
                                     KeyCode::Char('m') => app.set_sort_mode(SortMode::Mem),
+                                    KeyCode::Char('i') => app.set_sort_mode(SortMode::Io),
                                     KeyCode::Char('p') => app.set_sort_mode(SortMode::Pid),
                                     KeyCode::Char('n') => app.set_sort_mode(SortMode::Name),
-                                    KeyCode::Char('+') | KeyCode::Char('=') => {
+                                    KeyCode::Char(c) if c == app.keys.speed_up => {
                                         app.speed_up_refresh()
                                     }
-                                    KeyCode::Char('-') | KeyCode::Char('_') => {
+                                    KeyCode::Char('=') => app.speed_up_refresh(),
+                                    KeyCode::Char(c) if c == app.keys.speed_down => {
                                         app.slow_down_refresh()
                                     }
+                                    KeyCode::Char('_') => app.slow_down_refresh(),
                                     _ => {}
                                 }
                             }
@@ -793,25 +1690,88 @@ where
 }
 
 fn ui(f: &mut Frame, app: &mut App) {
+    let basic_height = if !app.basic_mode {
+        14
+    } else if app.per_core {
+        2
+    } else {
+        1
+    };
     let root = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(14),
+            Constraint::Length(basic_height),
             Constraint::Min(12),
             Constraint::Length(1),
         ])
         .split(f.area());
 
+    let proc_area = if app.basic_mode {
+        render_basic_summary(f, app, root[0]);
+        root[1]
+    } else {
+        render_rich_dashboard(f, app, root[0], root[1])
+    };
+
+    render_process_panel(f, app, proc_area, root[2]);
+}
+
+fn render_basic_summary(f: &mut Frame, app: &App, area: Rect) {
+    let cpu_usage = app.system.global_cpu_usage().clamp(0.0, 100.0);
+    let used_mem = app.system.used_memory();
+    let total_mem = app.system.total_memory();
+    let load = &app.load_avg;
+    let hottest = app
+        .components
+        .list()
+        .iter()
+        .filter_map(|c| c.temperature().map(|t| (c, t)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b));
+    let head = format!(
+        " CPU {:>4.1}%  MEM {:>5.1}/{:>5.1}G  NET ↓{} ↑{}  load {:.2} {:.2} {:.2}",
+        cpu_usage,
+        gib(used_mem),
+        gib(total_mem),
+        human_rate(&app.catalog, app.net_rx_rate),
+        human_rate(&app.catalog, app.net_tx_rate),
+        load.one,
+        load.five,
+        load.fifteen,
+    );
+    let mut spans = vec![Span::styled(head, Style::default().fg(Color::White))];
+    if let Some((c, temp)) = hottest {
+        spans.push(Span::styled(
+            format!("  {} {}", trim_text(c.label(), 10), format_temp(temp, app.temp_fahrenheit)),
+            Style::default().fg(temp_color(temp, c.critical())),
+        ));
+    }
+    let mut lines = vec![Line::from(spans)];
+    if app.per_core {
+        let cores: String = app
+            .core_usages
+            .iter()
+            .enumerate()
+            .map(|(i, u)| format!(" C{}{:<6}", i, bar(*u, 4)))
+            .collect();
+        lines.push(Line::from(cores));
+    }
+    f.render_widget(
+        Paragraph::new(lines),
+        area,
+    );
+}
+
+fn render_rich_dashboard(f: &mut Frame, app: &mut App, top_area: Rect, lower_area: Rect) -> Rect {
     let cpu_usage = app.system.global_cpu_usage().clamp(0.0, 100.0);
     let load = &app.load_avg;
     let refresh_ms = app.tick_rate.as_millis().min(9999);
 
     let top_block = Block::default()
         .borders(Borders::ALL)
-        .title("┌1 cpu┐ ┌menu┐ ┌preset *┐")
+        .title(tr(&app.catalog, "title.cpu"))
         .border_style(Style::default().fg(Color::DarkGray));
-    f.render_widget(top_block.clone(), root[0]);
-    let top_inner = top_block.inner(root[0]);
+    f.render_widget(top_block.clone(), top_area);
+    let top_inner = top_block.inner(top_area);
     let uptime = app.uptime_secs;
     f.render_widget(
         Paragraph::new(format!("up {}h {}m", uptime / 3600, (uptime % 3600) / 60))
@@ -849,11 +1809,9 @@ fn ui(f: &mut Frame, app: &mut App) {
             height: top_inner.height,
         };
         let history = app.cpu_history.make_contiguous();
+        let rows = braille_graph(history, 100, graph.width as usize, graph.height as usize);
         f.render_widget(
-            Sparkline::default()
-                .data(history.iter())
-                .max(100)
-                .style(Style::default().fg(Color::Green)),
+            Paragraph::new(rows.join("\n")).style(Style::default().fg(Color::Green)),
             graph,
         );
     }
@@ -884,10 +1842,14 @@ fn ui(f: &mut Frame, app: &mut App) {
     let lower = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
-        .split(root[1]);
+        .split(lower_area);
     let left = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(11), Constraint::Min(8)])
+        .constraints([
+            Constraint::Length(11),
+            Constraint::Length(7),
+            Constraint::Min(8),
+        ])
         .split(lower[0]);
     let upper_left = Layout::default()
         .direction(Direction::Horizontal)
@@ -896,7 +1858,9 @@ fn ui(f: &mut Frame, app: &mut App) {
     let lower_left = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(58), Constraint::Percentage(42)])
-        .split(left[1]);
+        .split(left[2]);
+
+    render_sensors(f, app, left[1]);
 
     let total_mem = app.system.total_memory();
     let used_mem = app.system.used_memory();
@@ -909,7 +1873,7 @@ fn ui(f: &mut Frame, app: &mut App) {
 
     let mem_block = Block::default()
         .borders(Borders::ALL)
-        .title("┌2 mem┐")
+        .title(tr(&app.catalog, "title.mem"))
         .border_style(Style::default().fg(Color::Red));
     let mem_inner = mem_block.inner(upper_left[0]);
     f.render_widget(mem_block, upper_left[0]);
@@ -935,7 +1899,7 @@ fn ui(f: &mut Frame, app: &mut App) {
 
     let disks_block = Block::default()
         .borders(Borders::ALL)
-        .title("┌disks┐")
+        .title(tr(&app.catalog, "title.disks"))
         .border_style(Style::default().fg(Color::Yellow));
     let disks_inner = disks_block.inner(upper_left[1]);
     f.render_widget(disks_block, upper_left[1]);
@@ -943,21 +1907,79 @@ fn ui(f: &mut Frame, app: &mut App) {
         .disks
         .list()
         .iter()
+        .filter(|disk| {
+            if !app.hide_pseudo_fs {
+                return true;
+            }
+            match app.mount_stats.get(disk.mount_point()) {
+                Some((fs_type, _, _)) => !is_pseudo_fs(fs_type),
+                None => true,
+            }
+        })
         .take(4)
         .map(|disk| {
             let total = disk.total_space();
             let avail = disk.available_space();
             let used = total.saturating_sub(avail);
             let used_pct = pct(used, total) as f32;
+            let device_name = Path::new(disk.name())
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let (read_rate, write_rate) = app
+                .disk_rates
+                .get(&device_name)
+                .copied()
+                .unwrap_or((0.0, 0.0));
+            let (total_read, total_written) = app
+                .prev_disk_bytes
+                .get(&device_name)
+                .copied()
+                .unwrap_or((0, 0));
+            let hist_width = disks_inner.width.saturating_sub(8) as usize;
+            let history_line = match app.disk_rate_history.get(&device_name) {
+                Some((read_hist, write_hist)) if hist_width > 0 => {
+                    let read_hist: Vec<u64> = read_hist.iter().copied().collect();
+                    let write_hist: Vec<u64> = write_hist.iter().copied().collect();
+                    let max = read_hist
+                        .iter()
+                        .chain(write_hist.iter())
+                        .copied()
+                        .max()
+                        .unwrap_or(1)
+                        .max(1);
+                    let r = braille_graph(&read_hist, max, hist_width, 1)
+                        .pop()
+                        .unwrap_or_default();
+                    let w = braille_graph(&write_hist, max, hist_width, 1)
+                        .pop()
+                        .unwrap_or_default();
+                    format!("\nR {r}\nW {w}")
+                }
+                _ => String::new(),
+            };
+            let (fs_type, inodes_total, inodes_free) = app
+                .mount_stats
+                .get(disk.mount_point())
+                .cloned()
+                .unwrap_or_else(|| ("?".to_string(), 0, 0));
+            let inodes_used_pct = pct(inodes_total.saturating_sub(inodes_free), inodes_total) as f32;
             format!(
-                "{}\nUsed:{:>4.0}% {:<14} {:>6.1}G\nFree:{:>4.0}% {:<14} {:>6.1}G",
+                "{} ({})\nUsed:{:>4.0}% {:<14} {:>6.1}G\nFree:{:>4.0}% {:<14} {:>6.1}G\nR:{:>9}/s  W:{:>9}/s\nTotal R:{:>9}  Total W:{:>9}{history_line}\nInodes:{:>4.0}% {:<14}",
                 friendly_mount(disk.mount_point()),
+                fs_type,
                 used_pct,
                 bar(used_pct, 10),
                 gib(used),
                 100.0 - used_pct,
                 bar(100.0 - used_pct, 10),
-                gib(avail)
+                gib(avail),
+                human_rate(&app.catalog, read_rate),
+                human_rate(&app.catalog, write_rate),
+                human_bytes(&app.catalog, total_read),
+                human_bytes(&app.catalog, total_written),
+                inodes_used_pct,
+                bar(inodes_used_pct, 10),
             )
         })
         .collect::<Vec<_>>()
@@ -974,7 +1996,7 @@ fn ui(f: &mut Frame, app: &mut App) {
 
     let net_block = Block::default()
         .borders(Borders::ALL)
-        .title(format!("┌3 net┐ {} {}", app.net_iface, app.net_ip))
+        .title(tr2(&app.catalog, "title.net", &app.net_iface, &app.net_ip))
         .border_style(Style::default().fg(Color::Blue));
     let net_inner = net_block.inner(lower_left[0]);
     f.render_widget(net_block, lower_left[0]);
@@ -989,36 +2011,47 @@ fn ui(f: &mut Frame, app: &mut App) {
     {
         let rx_hist = app.net_rx_history.make_contiguous();
         let rx_max = rx_hist.iter().copied().max().unwrap_or(1).max(1);
+        let rows = braille_graph(
+            rx_hist,
+            rx_max,
+            net_chunks[0].width as usize,
+            net_chunks[0].height as usize,
+        );
         f.render_widget(
-            Sparkline::default()
-                .data(rx_hist.iter())
-                .max(rx_max)
-                .style(Style::default().fg(Color::Cyan)),
+            Paragraph::new(rows.join("\n")).style(Style::default().fg(Color::Cyan)),
             net_chunks[0],
         );
     }
     {
         let tx_hist = app.net_tx_history.make_contiguous();
         let tx_max = tx_hist.iter().copied().max().unwrap_or(1).max(1);
+        let rows = braille_graph(
+            tx_hist,
+            tx_max,
+            net_chunks[1].width as usize,
+            net_chunks[1].height as usize,
+        );
         f.render_widget(
-            Sparkline::default()
-                .data(tx_hist.iter())
-                .max(tx_max)
-                .style(Style::default().fg(Color::Green)),
+            Paragraph::new(rows.join("\n")).style(Style::default().fg(Color::Green)),
             net_chunks[1],
         );
     }
+    let download_label = tr(&app.catalog, "net.download");
+    let upload_label = tr(&app.catalog, "net.upload");
+    let up_top_label = tr(&app.catalog, "net.up_top");
+    let total_rx_label = tr(&app.catalog, "net.total_rx");
+    let total_tx_label = tr(&app.catalog, "net.total_tx");
     f.render_widget(
         Paragraph::new(format!(
-            "download {:>8}/s {:<16}\nup top  {:>8}/s\n\ntotal rx {:>10}\n\nupload   {:>8}/s {:<16}\nup top  {:>8}/s\n\ntotal tx {:>10}",
-            human_rate(app.net_rx_rate),
+            "{download_label} {:>8}/s {:<16}\n{up_top_label}  {:>8}/s\n\n{total_rx_label} {:>10}\n\n{upload_label}   {:>8}/s {:<16}\n{up_top_label}  {:>8}/s\n\n{total_tx_label} {:>10}",
+            human_rate(&app.catalog, app.net_rx_rate),
             bar(rate_to_pct(app.net_rx_rate, app.net_rx_top), 10),
-            human_rate(app.net_rx_top),
-            human_bytes(app.net_rx_total),
-            human_rate(app.net_tx_rate),
+            human_rate(&app.catalog, app.net_rx_top),
+            human_bytes(&app.catalog, app.net_rx_total),
+            human_rate(&app.catalog, app.net_tx_rate),
             bar(rate_to_pct(app.net_tx_rate, app.net_tx_top), 10),
-            human_rate(app.net_tx_top),
-            human_bytes(app.net_tx_total),
+            human_rate(&app.catalog, app.net_tx_top),
+            human_bytes(&app.catalog, app.net_tx_total),
         ))
         .style(Style::default().fg(Color::White)),
         net_chunks[2],
@@ -1026,66 +2059,156 @@ fn ui(f: &mut Frame, app: &mut App) {
 
     let sync_block = Block::default()
         .borders(Borders::ALL)
-        .title("sync auto zero <b eth0 n>")
+        .title(tr(&app.catalog, "title.sync"))
         .border_style(Style::default().fg(Color::Blue));
     let sync_inner = sync_block.inner(lower_left[1]);
     f.render_widget(sync_block, lower_left[1]);
+    let top_label = tr(&app.catalog, "net.top");
+    let total_label = tr(&app.catalog, "net.total");
     f.render_widget(
         Paragraph::new(format!(
-            "download\n{:>8}/s\nTop:{:>9}/s\nTotal:{:>9}\n\nupload\n{:>8}/s\nTop:{:>9}/s\nTotal:{:>9}\n\n{}",
-            human_rate(app.net_rx_rate),
-            human_rate(app.net_rx_top),
-            human_bytes(app.net_rx_total),
-            human_rate(app.net_tx_rate),
-            human_rate(app.net_tx_top),
-            human_bytes(app.net_tx_total),
-            format!(
-                "sort={} rev={} tree={} lazy={} upd={:.1}ms draw={:.1}ms",
-                sort_name(app.sort_mode),
-                if app.sort_reverse { "on" } else { "off" },
-                if app.tree_mode { "on" } else { "off" },
-                if app.proc_lazy { "on" } else { "off" },
-                app.perf_update_ms_ema,
-                app.perf_draw_ms_ema
+            "{download_label}\n{:>8}/s\n{top_label}{:>9}/s\n{total_label}{:>9}\n\n{upload_label}\n{:>8}/s\n{top_label}{:>9}/s\n{total_label}{:>9}\n\n{}",
+            human_rate(&app.catalog, app.net_rx_rate),
+            human_rate(&app.catalog, app.net_rx_top),
+            human_bytes(&app.catalog, app.net_rx_total),
+            human_rate(&app.catalog, app.net_tx_rate),
+            human_rate(&app.catalog, app.net_tx_top),
+            human_bytes(&app.catalog, app.net_tx_total),
+            trn(
+                &app.catalog,
+                "net.sync_status",
+                &[
+                    sort_name(app.sort_mode),
+                    if app.sort_reverse { "on" } else { "off" },
+                    if app.tree_mode { "on" } else { "off" },
+                    if app.proc_lazy { "on" } else { "off" },
+                    &format!("{:.1}", app.perf_update_ms_ema),
+                    &format!("{:.1}", app.perf_draw_ms_ema),
+                ],
             ),
         ))
         .style(Style::default().fg(Color::White)),
         sync_inner,
     );
 
-    let proc_area = lower[1];
+    lower[1]
+}
+
+fn render_sensors(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            "┌sensors {}┐",
+            if app.temp_fahrenheit { "F" } else { "C" }
+        ))
+        .border_style(Style::default().fg(Color::Magenta));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let mut sensors: Vec<_> = app.components.list().iter().collect();
+    sensors.sort_by(|a, b| {
+        b.temperature()
+            .unwrap_or(f32::MIN)
+            .total_cmp(&a.temperature().unwrap_or(f32::MIN))
+    });
+
+    if sensors.is_empty() {
+        f.render_widget(
+            Paragraph::new(tr(&app.catalog, "sensors.none")).style(Style::default().fg(Color::Gray)),
+            inner,
+        );
+        return;
+    }
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(inner.height.saturating_sub(1).max(1)),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let max_lines = rows[0].height as usize;
+    let lines: Vec<Line> = sensors
+        .iter()
+        .take(max_lines)
+        .filter_map(|c| {
+            let temp = c.temperature()?;
+            let critical = c.critical();
+            let pct = critical
+                .filter(|c| *c > 0.0)
+                .map_or(0.0, |c| (temp / c * 100.0).clamp(0.0, 100.0));
+            Some(Line::styled(
+                format!(
+                    "{:<14} {:<8} {:>5}",
+                    trim_text(c.label(), 14),
+                    bar(pct, 8),
+                    format_temp(temp, app.temp_fahrenheit)
+                ),
+                Style::default().fg(temp_color(temp, critical)),
+            ))
+        })
+        .collect();
+    f.render_widget(Paragraph::new(lines), rows[0]);
+
+    if let Some(hottest) = sensors.first() {
+        if let Some(history) = app.sensor_history.get(hottest.label()) {
+            let data: Vec<u64> = history.iter().map(|t| *t as u64).collect();
+            let max = data.iter().copied().max().unwrap_or(1).max(1);
+            f.render_widget(
+                Sparkline::default()
+                    .data(data.iter())
+                    .max(max)
+                    .style(Style::default().fg(Color::Magenta)),
+                rows[1],
+            );
+        }
+    }
+}
+
+fn render_process_panel(f: &mut Frame, app: &mut App, proc_area: Rect, footer_area: Rect) {
     let table_visible_rows = proc_area.height.saturating_sub(3) as usize;
     app.last_table_visible_rows = table_visible_rows.max(1);
     app.align_scroll_to_selection(table_visible_rows);
     let start = app.process_scroll.min(app.process_count());
     let end = (start + table_visible_rows).min(app.process_count());
 
-    let cpu_header = if matches!(app.sort_mode, SortMode::Cpu) {
-        if app.sort_reverse {
-            "Cpu%↑"
-        } else {
-            "Cpu%↓"
-        }
-    } else {
-        "Cpu%"
-    };
-    let mem_header = if matches!(app.sort_mode, SortMode::Mem) {
-        if app.sort_reverse {
-            "MemB↑"
-        } else {
-            "MemB↓"
-        }
-    } else {
-        "MemB"
-    };
-    let pid_header = if matches!(app.sort_mode, SortMode::Pid) {
-        if app.sort_reverse { "Pid↓" } else { "Pid↑" }
-    } else {
-        "Pid"
-    };
+    let cpu_header = sort_header(
+        tr(&app.catalog, "table.cpu"),
+        matches!(app.sort_mode, SortMode::Cpu),
+        app.sort_reverse,
+    );
+    let mem_header = sort_header(
+        tr(&app.catalog, "table.mem"),
+        matches!(app.sort_mode, SortMode::Mem),
+        app.sort_reverse,
+    );
+    let pid_header = sort_header_inv(
+        tr(&app.catalog, "table.pid"),
+        matches!(app.sort_mode, SortMode::Pid),
+        app.sort_reverse,
+    );
+    let read_header = sort_header(
+        tr(&app.catalog, "table.read"),
+        matches!(app.sort_mode, SortMode::Io),
+        app.sort_reverse,
+    );
+    let write_header = sort_header(
+        tr(&app.catalog, "table.write"),
+        matches!(app.sort_mode, SortMode::Io),
+        app.sort_reverse,
+    );
     let header = Row::new(
         [
-            pid_header, "Program:", "Command:", "Threads:", "User:", mem_header, cpu_header,
+            pid_header,
+            tr(&app.catalog, "table.program").to_string(),
+            tr(&app.catalog, "table.command").to_string(),
+            tr(&app.catalog, "table.threads").to_string(),
+            tr(&app.catalog, "table.user").to_string(),
+            mem_header,
+            cpu_header,
+            read_header,
+            write_header,
         ]
         .into_iter()
         .map(Cell::from),
@@ -1120,6 +2243,8 @@ fn ui(f: &mut Frame, app: &mut App) {
                 } else {
                     p.cpu_cell_total.as_str()
                 }),
+                Cell::from(p.disk_read_text.as_str()),
+                Cell::from(p.disk_write_text.as_str()),
             ])
             .style(row_style)
         });
@@ -1127,13 +2252,15 @@ fn ui(f: &mut Frame, app: &mut App) {
     let table = Table::new(
         rows,
         [
-            Constraint::Percentage(10),
-            Constraint::Percentage(15),
-            Constraint::Percentage(35),
             Constraint::Percentage(8),
             Constraint::Percentage(12),
+            Constraint::Percentage(24),
+            Constraint::Percentage(6),
+            Constraint::Percentage(10),
             Constraint::Percentage(8),
-            Constraint::Percentage(12),
+            Constraint::Percentage(10),
+            Constraint::Percentage(11),
+            Constraint::Percentage(11),
         ],
     )
     .header(header)
@@ -1141,11 +2268,15 @@ fn ui(f: &mut Frame, app: &mut App) {
         Block::default()
             .borders(Borders::ALL)
             .title(format!(
-                "┌4 proc┐ {} ┌per-core {}┐ ┌reverse {}┐ ┌tree {}┐ < {} {} >",
+                "┌4 proc┐ {} ┌per-core {}┐ ┌reverse {}┐ ┌tree {}┐{} < {} {} >",
                 filter_mode_label(app),
                 if app.per_core { "on" } else { "off" },
                 if app.sort_reverse { "on" } else { "off" },
                 if app.tree_mode { "on" } else { "off" },
+                match app.follow_pid {
+                    Some(pid) => format!(" ┌following {pid}┐"),
+                    None => String::new(),
+                },
                 sort_name(app.sort_mode),
                 if app.proc_lazy { "lazy" } else { "resp" }
             ))
@@ -1154,9 +2285,11 @@ fn ui(f: &mut Frame, app: &mut App) {
     f.render_widget(table, proc_area);
 
     let footer_left = match app.input_mode {
-        InputMode::Filter => " ↑↓ select  / search: ACTIVE  Esc/Enter done  x clear  q quit ",
+        InputMode::Filter => {
+            " / search: ACTIVE  Tab mode  ←→/Home/End cursor  Esc/Enter done "
+        }
         InputMode::Normal => {
-            " ↑/↓ select  / search: inactive  x clear  t term  k kill  s signals  ←/→ sort  r rev  e per-core  w tree  l lazy  q quit "
+            " ↑/↓ select  / search: inactive  x clear  t term  k kill  s signals  ←/→ sort  r rev  e per-core  w tree  l lazy  F follow  b basic  u °C/°F  Ctrl+S save cfg  ? help  q quit "
         }
     };
     let current = if app.process_count() == 0 {
@@ -1170,25 +2303,25 @@ fn ui(f: &mut Frame, app: &mut App) {
         app.perf_draw_ms_ema,
         app.process_count()
     );
-    let width = root[2].width as usize;
+    let width = footer_area.width as usize;
     let right_len = footer_right.chars().count();
     if width <= right_len + 1 {
         f.render_widget(
             Paragraph::new(trim_text(&footer_right, width))
                 .style(Style::default().fg(Color::DarkGray)),
-            root[2],
+            footer_area,
         );
     } else {
         let left_width = width - right_len - 1;
         let left_rect = Rect {
-            x: root[2].x,
-            y: root[2].y,
+            x: footer_area.x,
+            y: footer_area.y,
             width: left_width as u16,
             height: 1,
         };
         let right_rect = Rect {
-            x: root[2].x + left_width as u16 + 1,
-            y: root[2].y,
+            x: footer_area.x + left_width as u16 + 1,
+            y: footer_area.y,
             width: right_len as u16,
             height: 1,
         };
@@ -1203,22 +2336,29 @@ fn ui(f: &mut Frame, app: &mut App) {
         );
     }
 
-    if !app.status_msg.is_empty() {
+    if app.filter_invalid || !app.status_msg.is_empty() {
         let status_area = Rect {
-            x: root[2].x,
-            y: root[2].y.saturating_sub(1),
-            width: root[2].width.min(f.area().width),
+            x: footer_area.x,
+            y: footer_area.y.saturating_sub(1),
+            width: footer_area.width.min(f.area().width),
             height: 1,
         };
-        f.render_widget(
-            Paragraph::new(format!(" {}", app.status_msg))
-                .style(Style::default().fg(Color::Yellow)),
-            status_area,
-        );
+        let (text, style) = if app.filter_invalid {
+            (
+                format!(" invalid regex: {}", app.filter_query),
+                Style::default().fg(Color::Red),
+            )
+        } else {
+            (format!(" {}", app.status_msg), Style::default().fg(Color::Yellow))
+        };
+        f.render_widget(Paragraph::new(text).style(style), status_area);
     }
 
     if let Some(modal) = &app.modal {
-        let area = centered_rect(56, 8, f.area());
+        let area = match modal {
+            ModalState::Help { .. } => centered_rect(70, 24, f.area()),
+            _ => centered_rect(56, 8, f.area()),
+        };
         f.render_widget(Clear, area);
         match modal {
             ModalState::Confirm {
@@ -1229,7 +2369,7 @@ fn ui(f: &mut Frame, app: &mut App) {
             } => {
                 let block = Block::default()
                     .borders(Borders::ALL)
-                    .title("confirm signal")
+                    .title(tr(&app.catalog, "title.confirm_signal"))
                     .border_style(Style::default().fg(Color::Red));
                 let inner = block.inner(area);
                 f.render_widget(block, area);
@@ -1245,7 +2385,7 @@ fn ui(f: &mut Frame, app: &mut App) {
             ModalState::SignalPicker { selected } => {
                 let block = Block::default()
                     .borders(Borders::ALL)
-                    .title("select signal")
+                    .title(tr(&app.catalog, "title.select_signal"))
                     .border_style(Style::default().fg(Color::Red));
                 let inner = block.inner(area);
                 f.render_widget(block, area);
@@ -1269,6 +2409,33 @@ fn ui(f: &mut Frame, app: &mut App) {
                     inner,
                 );
             }
+            ModalState::Help { scroll } => {
+                let block = Block::default()
+                    .borders(Borders::ALL)
+                    .title(tr(&app.catalog, "title.help"))
+                    .border_style(Style::default().fg(Color::Cyan));
+                let inner = block.inner(area);
+                f.render_widget(block, area);
+                let text = trn(
+                    &app.catalog,
+                    "help.body",
+                    &[
+                        &app.keys.cycle_sort.to_string(),
+                        &app.keys.reverse.to_string(),
+                        &app.keys.per_core.to_string(),
+                        &app.keys.tree.to_string(),
+                        &app.keys.lazy.to_string(),
+                        &app.keys.speed_up.to_string(),
+                        &app.keys.speed_down.to_string(),
+                    ],
+                );
+                f.render_widget(
+                    Paragraph::new(text)
+                        .style(Style::default().fg(Color::White))
+                        .scroll((*scroll, 0)),
+                    inner,
+                );
+            }
         }
     }
 }
@@ -1371,29 +2538,55 @@ fn walk_tree(
     }
 }
 
+/// Appends a sort-direction arrow to a column header when it's the active
+/// sort column: `reverse` shows `↑`, otherwise `↓`.
+fn sort_header(label: &str, active: bool, reverse: bool) -> String {
+    if !active {
+        label.to_string()
+    } else if reverse {
+        format!("{label}↑")
+    } else {
+        format!("{label}↓")
+    }
+}
+
+/// Same as [`sort_header`] but with the arrows swapped, matching the pid
+/// column's historical (inverted) direction convention.
+fn sort_header_inv(label: &str, active: bool, reverse: bool) -> String {
+    if !active {
+        label.to_string()
+    } else if reverse {
+        format!("{label}↓")
+    } else {
+        format!("{label}↑")
+    }
+}
+
 fn sort_name(mode: SortMode) -> &'static str {
     match mode {
         SortMode::Cpu => "cpu",
         SortMode::Mem => "mem",
+        SortMode::Io => "io",
         SortMode::Pid => "pid",
         SortMode::Name => "name",
     }
 }
 
 fn filter_mode_label(app: &App) -> String {
+    let mode = app.filter_mode.label();
     match app.input_mode {
         InputMode::Filter => {
             if app.filter_query.is_empty() {
-                "f _◄".to_string()
+                tr1(&app.catalog, "filter.input_empty", mode)
             } else {
-                format!("f {}◄", app.filter_query)
+                tr2(&app.catalog, "filter.input_active", mode, &app.filter_query)
             }
         }
         InputMode::Normal => {
             if app.filter_query.is_empty() {
-                "filter off".to_string()
+                tr(&app.catalog, "filter.off").to_string()
             } else {
-                format!("filter {}", app.filter_query)
+                tr2(&app.catalog, "filter.active", mode, &app.filter_query)
             }
         }
     }
@@ -1408,6 +2601,62 @@ fn bar(value: f32, width: usize) -> String {
     s
 }
 
+/// Builds the 25-entry braille dot table, indexed `[left * 5 + right]`, where
+/// `left`/`right` are column heights in dots (0 = blank, 4 = full column).
+fn braille_table() -> [char; 25] {
+    const LEFT_BITS: [u8; 4] = [0x01, 0x02, 0x04, 0x40];
+    const RIGHT_BITS: [u8; 4] = [0x08, 0x10, 0x20, 0x80];
+    let mut table = ['\u{2800}'; 25];
+    for left in 0..=4usize {
+        for right in 0..=4usize {
+            let mut bits = 0u8;
+            for row in 0..4 {
+                if row >= 4 - left {
+                    bits |= LEFT_BITS[row];
+                }
+                if row >= 4 - right {
+                    bits |= RIGHT_BITS[row];
+                }
+            }
+            table[left * 5 + right] = char::from_u32(0x2800 + bits as u32).unwrap();
+        }
+    }
+    table
+}
+
+/// Renders `data` as a bottom-aligned braille line graph `height` rows tall and
+/// `width` columns wide. Each column packs two consecutive samples (one per
+/// braille sub-column), roughly doubling the horizontal resolution of a
+/// block `Sparkline` at the same width.
+fn braille_graph(data: &[u64], max: u64, width: usize, height: usize) -> Vec<String> {
+    let table = braille_table();
+    if width == 0 || height == 0 {
+        return Vec::new();
+    }
+    let max = max.max(1) as f64;
+    let sub_max = (height * 4) as f64;
+    let samples_needed = width * 2;
+    let start = data.len().saturating_sub(samples_needed);
+    let slice = &data[start..];
+    let mut padded = vec![0u64; samples_needed - slice.len()];
+    padded.extend_from_slice(slice);
+
+    let level = |v: u64| -> usize { ((v as f64 / max) * sub_max).round().clamp(0.0, sub_max) as usize };
+
+    let mut rows = vec![String::with_capacity(width); height];
+    for col in 0..width {
+        let l_level = level(padded[col * 2]);
+        let r_level = level(padded[col * 2 + 1]);
+        for (row, line) in rows.iter_mut().enumerate() {
+            let row_base = (height - 1 - row) * 4;
+            let l_bucket = l_level.saturating_sub(row_base).min(4);
+            let r_bucket = r_level.saturating_sub(row_base).min(4);
+            line.push(table[l_bucket * 5 + r_bucket]);
+        }
+    }
+    rows
+}
+
 fn friendly_mount(path: &Path) -> String {
     let s = path.to_string_lossy();
     if s == "/" {
@@ -1425,16 +2674,106 @@ fn friendly_mount(path: &Path) -> String {
     }
 }
 
-fn human_rate(bytes_per_sec: f64) -> String {
+/// Reads cumulative per-device sector counters from `/proc/diskstats`, keyed
+/// by device name (e.g. `sda1`), converted to bytes. Returns an empty map on
+/// platforms without procfs rather than erroring.
+fn read_diskstats() -> HashMap<String, (u64, u64)> {
+    const SECTOR_SIZE: u64 = 512;
+    let mut map = HashMap::new();
+    if let Ok(content) = fs::read_to_string("/proc/diskstats") {
+        for line in content.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 {
+                continue;
+            }
+            let name = fields[2].to_string();
+            let sectors_read: u64 = fields[5].parse().unwrap_or(0);
+            let sectors_written: u64 = fields[9].parse().unwrap_or(0);
+            map.insert(name, (sectors_read * SECTOR_SIZE, sectors_written * SECTOR_SIZE));
+        }
+    }
+    map
+}
+
+/// Filesystem types that are kernel-internal rather than real storage; shown
+/// only when the user explicitly asks to unhide them.
+const PSEUDO_FILESYSTEMS: &[&str] = &[
+    "tmpfs", "proc", "sysfs", "cgroup", "cgroup2", "overlay", "overlayfs", "devtmpfs", "devpts",
+    "mqueue", "debugfs", "tracefs", "securityfs", "pstore", "bpf", "autofs", "configfs", "ramfs",
+];
+
+fn is_pseudo_fs(fs_type: &str) -> bool {
+    PSEUDO_FILESYSTEMS.contains(&fs_type)
+}
+
+/// Reads the filesystem type of each mount point from `/proc/mounts`, keyed
+/// by mount path, since `sysinfo::Disk` doesn't expose it.
+fn read_mount_fs_types() -> HashMap<PathBuf, String> {
+    let mut map = HashMap::new();
+    if let Ok(content) = fs::read_to_string("/proc/mounts") {
+        for line in content.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 3 {
+                continue;
+            }
+            map.insert(PathBuf::from(fields[1]), fields[2].to_string());
+        }
+    }
+    map
+}
+
+/// Calls `statvfs` on a mount point and returns `(total inodes, free inodes)`,
+/// the one stat byte-based disk usage can't see: a filesystem can have free
+/// space left and still be unable to create a new file because it's out of
+/// inodes.
+fn statvfs_inodes(mount_point: &Path) -> Option<(u64, u64)> {
+    let c_path = CString::new(mount_point.as_os_str().as_bytes()).ok()?;
+    let mut buf: libc::statvfs = unsafe { std::mem::zeroed() };
+    let rc = unsafe { libc::statvfs(c_path.as_ptr(), &mut buf) };
+    if rc != 0 {
+        return None;
+    }
+    Some((buf.f_files, buf.f_ffree))
+}
+
+fn human_rate(catalog: &Catalog, bytes_per_sec: f64) -> String {
     let b = bytes_per_sec.max(0.0);
     if b >= 1_073_741_824.0 {
-        format!("{:.1} GiB", b / 1_073_741_824.0)
+        format!("{:.1} {}", b / 1_073_741_824.0, tr(catalog, "unit.gib"))
     } else if b >= 1_048_576.0 {
-        format!("{:.1} MiB", b / 1_048_576.0)
+        format!("{:.1} {}", b / 1_048_576.0, tr(catalog, "unit.mib"))
     } else if b >= 1024.0 {
-        format!("{:.1} KiB", b / 1024.0)
+        format!("{:.1} {}", b / 1024.0, tr(catalog, "unit.kib"))
+    } else {
+        format!("{:.0} {}", b, tr(catalog, "unit.b"))
+    }
+}
+
+fn celsius_to_fahrenheit(celsius: f32) -> f32 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+fn format_temp(celsius: f32, fahrenheit: bool) -> String {
+    if fahrenheit {
+        format!("{:.0}°F", celsius_to_fahrenheit(celsius))
     } else {
-        format!("{:.0} B", b)
+        format!("{:.0}°C", celsius)
+    }
+}
+
+fn temp_color(celsius: f32, critical: Option<f32>) -> Color {
+    match critical {
+        Some(crit) if crit > 0.0 => {
+            let ratio = celsius / crit;
+            if ratio >= 0.9 {
+                Color::Red
+            } else if ratio >= 0.7 {
+                Color::Yellow
+            } else {
+                Color::Green
+            }
+        }
+        _ => Color::Green,
     }
 }
 
@@ -1446,6 +2785,35 @@ fn rate_to_pct(current: f64, top: f64) -> f32 {
     }
 }
 
+fn insert_char_at(s: &mut String, idx: usize, c: char) {
+    let byte_idx = s.char_indices().nth(idx).map(|(i, _)| i).unwrap_or(s.len());
+    s.insert(byte_idx, c);
+}
+
+fn remove_char_at(s: &mut String, idx: usize) {
+    if let Some((byte_idx, ch)) = s.char_indices().nth(idx) {
+        s.drain(byte_idx..byte_idx + ch.len_utf8());
+    }
+}
+
+/// True if every character of `query` appears in `text`, in order, not
+/// necessarily contiguously (e.g. `chrm` matches `chrome`).
+fn fuzzy_subsequence_match(text: &str, query: &str) -> bool {
+    if query.is_empty() {
+        return true;
+    }
+    let mut chars = text.chars();
+    'query: for qc in query.chars() {
+        for tc in chars.by_ref() {
+            if tc == qc {
+                continue 'query;
+            }
+        }
+        return false;
+    }
+    true
+}
+
 fn trim_text(s: &str, max_chars: usize) -> String {
     let mut chars = s.chars();
     let out: String = chars.by_ref().take(max_chars).collect();
@@ -1463,6 +2831,89 @@ fn detect_local_ip() -> Option<String> {
     Some(addr.ip().to_string())
 }
 
+/// Latest sampled values exposed over `/metrics`, refreshed once per `App::update`.
+#[derive(Default, Clone)]
+struct MetricsSnapshot {
+    cpu_usage: f32,
+    mem_used: u64,
+    mem_total: u64,
+    net_iface: String,
+    net_rx_rate: f64,
+    net_tx_rate: f64,
+    disks: Vec<(String, u64, u64)>,
+}
+
+type SharedMetrics = Arc<Mutex<MetricsSnapshot>>;
+
+/// Binds `addr` and serves Prometheus text-format metrics at `/metrics` from a
+/// background thread, reading `App::update`'s latest snapshot on each request.
+fn spawn_metrics_server(addr: &str, metrics: SharedMetrics) -> io::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            handle_metrics_request(stream, &metrics);
+        }
+    });
+    Ok(())
+}
+
+fn handle_metrics_request(mut stream: TcpStream, metrics: &SharedMetrics) {
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+    let mut buf = [0u8; 1024];
+    let Ok(n) = stream.read(&mut buf) else {
+        return;
+    };
+    let request = String::from_utf8_lossy(&buf[..n]);
+    let request_line = request.lines().next().unwrap_or("");
+    let (status, body) = if request_line.starts_with("GET /metrics") {
+        let snapshot = metrics.lock().unwrap().clone();
+        ("200 OK", render_prometheus_metrics(&snapshot))
+    } else {
+        ("404 Not Found", String::new())
+    };
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes());
+}
+
+fn render_prometheus_metrics(snap: &MetricsSnapshot) -> String {
+    let mut out = String::new();
+    out.push_str("# HELP utop_cpu_usage_percent Overall CPU usage percentage.\n");
+    out.push_str("# TYPE utop_cpu_usage_percent gauge\n");
+    out.push_str(&format!("utop_cpu_usage_percent {:.2}\n", snap.cpu_usage));
+    out.push_str("# HELP utop_mem_used_bytes Used memory in bytes.\n");
+    out.push_str("# TYPE utop_mem_used_bytes gauge\n");
+    out.push_str(&format!("utop_mem_used_bytes {}\n", snap.mem_used));
+    out.push_str("# HELP utop_mem_total_bytes Total memory in bytes.\n");
+    out.push_str("# TYPE utop_mem_total_bytes gauge\n");
+    out.push_str(&format!("utop_mem_total_bytes {}\n", snap.mem_total));
+    out.push_str("# HELP utop_net_rx_bytes_per_second Network receive rate.\n");
+    out.push_str("# TYPE utop_net_rx_bytes_per_second gauge\n");
+    out.push_str(&format!(
+        "utop_net_rx_bytes_per_second{{iface=\"{}\"}} {:.2}\n",
+        snap.net_iface, snap.net_rx_rate
+    ));
+    out.push_str("# HELP utop_net_tx_bytes_per_second Network transmit rate.\n");
+    out.push_str("# TYPE utop_net_tx_bytes_per_second gauge\n");
+    out.push_str(&format!(
+        "utop_net_tx_bytes_per_second{{iface=\"{}\"}} {:.2}\n",
+        snap.net_iface, snap.net_tx_rate
+    ));
+    out.push_str("# HELP utop_disk_used_bytes Used disk space per mount.\n");
+    out.push_str("# TYPE utop_disk_used_bytes gauge\n");
+    for (mount, used, _total) in &snap.disks {
+        out.push_str(&format!("utop_disk_used_bytes{{mount=\"{mount}\"}} {used}\n"));
+    }
+    out.push_str("# HELP utop_disk_total_bytes Total disk space per mount.\n");
+    out.push_str("# TYPE utop_disk_total_bytes gauge\n");
+    for (mount, _used, total) in &snap.disks {
+        out.push_str(&format!("utop_disk_total_bytes{{mount=\"{mount}\"}} {total}\n"));
+    }
+    out
+}
+
 fn gib(bytes: u64) -> f64 {
     bytes as f64 / 1_073_741_824.0
 }
@@ -1475,19 +2926,19 @@ fn pct(part: u64, total: u64) -> f64 {
     }
 }
 
-fn human_bytes(b: u64) -> String {
+fn human_bytes(catalog: &Catalog, b: u64) -> String {
     let kib = 1024.0;
     let mib = kib * 1024.0;
     let gib = mib * 1024.0;
     let bf = b as f64;
     if bf >= gib {
-        format!("{:.1}G", bf / gib)
+        format!("{:.1}{}", bf / gib, tr(catalog, "unit.g"))
     } else if bf >= mib {
-        format!("{:.0}M", bf / mib)
+        format!("{:.0}{}", bf / mib, tr(catalog, "unit.m"))
     } else if bf >= kib {
-        format!("{:.0}K", bf / kib)
+        format!("{:.0}{}", bf / kib, tr(catalog, "unit.k"))
     } else {
-        format!("{}B", b)
+        format!("{}{}", b, tr(catalog, "unit.b"))
     }
 }
 